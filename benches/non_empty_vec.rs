@@ -0,0 +1,56 @@
+//! Wall-clock benchmarks of the single- and few-error accumulation paths
+//! that motivated `NonEmptyVec`'s inline tail storage. These time
+//! construction and merging only — `BatchSize::SmallInput` keeps each
+//! closure's setup (e.g. the `vec![2, 3]`/`NonEmptyVec` inputs) out of the
+//! timed region, since that allocation happens regardless of how the tail
+//! is stored. `non_empty_vec::tests::append_of_two_inline_tails_does_not_allocate`
+//! is the allocation-counting companion to `merging_two_inline_tails`
+//! below — wall-clock timing alone can't prove the absence of an
+//! allocation. Run with `cargo bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use fp_validation::NonEmptyVec;
+
+fn single_error(c: &mut Criterion) {
+    c.bench_function("non_empty_vec_single_error", |b| {
+        b.iter(|| black_box(NonEmptyVec::from(black_box(42))))
+    });
+}
+
+fn few_errors_within_inline_capacity(c: &mut Criterion) {
+    c.bench_function("non_empty_vec_three_errors", |b| {
+        b.iter_batched(
+            || vec![2, 3],
+            |tail| black_box(NonEmptyVec::new(black_box(1), tail)),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn many_errors_spilling_to_heap(c: &mut Criterion) {
+    c.bench_function("non_empty_vec_ten_errors", |b| {
+        b.iter(|| black_box(NonEmptyVec::new(black_box(0), 1..10)))
+    });
+}
+
+fn merging_two_inline_tails(c: &mut Criterion) {
+    c.bench_function("non_empty_vec_append_two_inline_tails", |b| {
+        b.iter_batched(
+            || (NonEmptyVec::new(1, vec![2]), NonEmptyVec::new(3, vec![4])),
+            |(mut errors, other)| {
+                errors.append(other);
+                black_box(errors)
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(
+    benches,
+    single_error,
+    few_errors_within_inline_capacity,
+    many_errors_spilling_to_heap,
+    merging_two_inline_tails
+);
+criterion_main!(benches);
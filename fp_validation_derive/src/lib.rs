@@ -0,0 +1,285 @@
+//! Derives `Validate` for a "raw" struct, generating an applicative
+//! `ap`-chain equivalent to the one you'd otherwise hand-write with
+//! `fp_validation::Validation`.
+//!
+//! ```ignore
+//! #[derive(Validate)]
+//! #[validate(raw = "PersonRaw", error = "PersonValidationError")]
+//! struct Person {
+//!     #[validate(with = "Email::validate", map_errs = "|e| PersonValidationError::InvalidEmail(e.head)")]
+//!     email: Email,
+//!     #[validate(with = "FullName::validate", map_errs = "|_| PersonValidationError::InvalidFullName")]
+//!     name: FullName,
+//!     #[validate(with = "PhoneNumber::validate", map_errs = "PersonValidationError::InvalidPhoneNumber")]
+//!     phone: PhoneNumber,
+//!     #[validate(length(min = 1, max = 64), map_errs = "PersonValidationError::InvalidBio")]
+//!     bio: String,
+//! }
+//! ```
+//!
+//! expands to a `Person::validate(raw: PersonRaw) -> Validation<Person, PersonValidationError>`
+//! that folds every field's `Validation` together with `ap`, so every field
+//! error is accumulated rather than short-circuited.
+//!
+//! Every field needs either `with = "..."`, naming a `Fn(FieldType) ->
+//! Validation<T, E>` to call, or one of the built-in shorthands below, which
+//! validate the raw field in place (so the struct field's type must match
+//! the raw field's type) and report [`fp_validation::validators::ValidationError`]:
+//!
+//! - `length(min = ..., max = ...)` — [`fp_validation::validators::length`], for `String` fields.
+//! - `range(min = ..., max = ...)` — [`fp_validation::validators::range`], for `Copy + PartialOrd` fields.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Expr, Fields, Ident, Lit, Meta,
+    MetaNameValue, Token, Type,
+};
+
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct FieldSpec {
+    ident: Ident,
+    validator: Expr,
+    map_errs: Expr,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let struct_ident = &input.ident;
+
+    let fields = match input.data {
+        Data::Struct(ref data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    struct_ident,
+                    "#[derive(Validate)] only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                struct_ident,
+                "#[derive(Validate)] can only be applied to structs",
+            ))
+        }
+    };
+
+    let raw_ty = parse_struct_attr(&input.attrs, "raw")?
+        .map(|expr| expr_to_type(&expr))
+        .transpose()?
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                struct_ident,
+                "#[validate(raw = \"...\")] is required to name the unvalidated input struct",
+            )
+        })?;
+
+    let error_ty = match parse_struct_attr(&input.attrs, "error")? {
+        Some(expr) => expr_to_type(&expr)?,
+        None => {
+            let default_error = Ident::new(&format!("{}ValidationError", struct_ident), Span::call_site());
+            syn::parse_quote!(#default_error)
+        }
+    };
+
+    let mut specs = Vec::with_capacity(fields.len());
+    for field in fields {
+        let ident = field.ident.clone().expect("named field");
+        let (validator, map_errs) = parse_field_attr(&field.attrs, &ident)?;
+        specs.push(FieldSpec {
+            ident,
+            validator,
+            map_errs,
+        });
+    }
+
+    if specs.is_empty() {
+        return Err(syn::Error::new_spanned(
+            struct_ident,
+            "#[derive(Validate)] needs at least one field",
+        ));
+    }
+
+    // Build the curried constructor: a nested closure that takes the fields
+    // in declaration order and produces `struct_ident { .. }`, with the last
+    // field as the outermost argument. That lets the chain below `.map` the
+    // last field's `Validation` onto the constructor and then `.ap` every
+    // earlier field in reverse, so each field's errors become the new
+    // `self` side of `ap` and the already-folded tail is appended after it
+    // — which keeps the final error order matching declaration order
+    // (first field ends up as the head, not the last).
+    let field_idents: Vec<_> = specs.iter().map(|spec| spec.ident.clone()).collect();
+    let mut constructor = quote! {
+        #struct_ident { #( #field_idents ),* }
+    };
+    for ident in &field_idents {
+        constructor = quote! { move |#ident| #constructor };
+    }
+
+    let mut specs_rev = specs.iter().rev();
+    let last = specs_rev.next().expect("checked non-empty above");
+    let FieldSpec {
+        ident: last_ident,
+        validator: last_validator,
+        map_errs: last_map_errs,
+    } = last;
+    let mut chain = quote! {
+        (#last_validator)(raw.#last_ident)
+            .map_errs(#last_map_errs)
+            .map(#constructor)
+    };
+    for spec in specs_rev {
+        let FieldSpec {
+            ident,
+            validator,
+            map_errs,
+        } = spec;
+        chain = quote! {
+            (#validator)(raw.#ident)
+                .map_errs(#map_errs)
+                .ap(#chain)
+        };
+    }
+
+    Ok(quote! {
+        impl #struct_ident {
+            pub fn validate(raw: #raw_ty) -> fp_validation::Validation<#struct_ident, #error_ty> {
+                #chain
+            }
+        }
+    })
+}
+
+fn parse_struct_attr(attrs: &[syn::Attribute], name: &str) -> syn::Result<Option<Expr>> {
+    for attr in attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
+        }
+
+        let pairs = attr.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+        for pair in pairs {
+            if pair.path.is_ident(name) {
+                return Ok(Some(pair.value));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn parse_field_attr(attrs: &[syn::Attribute], field_ident: &Ident) -> syn::Result<(Expr, Expr)> {
+    for attr in attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
+        }
+
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        let mut with = None;
+        let mut map_errs = None;
+
+        for meta in metas {
+            match meta {
+                Meta::NameValue(nv) if nv.path.is_ident("with") => {
+                    with = Some(lit_str_to_expr(&nv.value)?);
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("map_errs") => {
+                    map_errs = Some(lit_str_to_expr(&nv.value)?);
+                }
+                Meta::List(list) if list.path.is_ident("length") => {
+                    with = Some(length_shorthand(&list)?);
+                }
+                Meta::List(list) if list.path.is_ident("range") => {
+                    with = Some(range_shorthand(&list)?);
+                }
+                _ => {}
+            }
+        }
+
+        let with = with.ok_or_else(|| {
+            syn::Error::new_spanned(
+                field_ident,
+                "expected #[validate(with = \"...\")] naming the field validator, or a built-in \
+                 shorthand like #[validate(length(min = ..., max = ...))]",
+            )
+        })?;
+        let map_errs = map_errs.unwrap_or_else(|| syn::parse_quote!(::std::convert::identity));
+
+        return Ok((with, map_errs));
+    }
+
+    Err(syn::Error::new_spanned(
+        field_ident,
+        "every field of a #[derive(Validate)] struct needs a #[validate(..)] attribute",
+    ))
+}
+
+/// `length(min = ..., max = ...)` checks the raw `String` field in place and
+/// passes it through unchanged on success, since there's no separate "with"
+/// function to hand the value to.
+fn length_shorthand(list: &syn::MetaList) -> syn::Result<Expr> {
+    let (min, max) = parse_min_max(list)?;
+    Ok(syn::parse_quote! {
+        |raw: ::std::string::String| match fp_validation::validators::length(#min, #max)(&raw) {
+            fp_validation::Validation::Ok(()) => fp_validation::Validation::Ok(raw),
+            fp_validation::Validation::Errs(errors) => fp_validation::Validation::Errs(errors),
+        }
+    })
+}
+
+/// `range(min = ..., max = ...)` delegates straight to
+/// [`fp_validation::validators::range`], which already validates and passes
+/// the value through in one step.
+fn range_shorthand(list: &syn::MetaList) -> syn::Result<Expr> {
+    let (min, max) = parse_min_max(list)?;
+    Ok(syn::parse_quote! {
+        fp_validation::validators::range(#min, #max)
+    })
+}
+
+fn parse_min_max(list: &syn::MetaList) -> syn::Result<(Expr, Expr)> {
+    let pairs = list.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+    let mut min = None;
+    let mut max = None;
+
+    for pair in pairs {
+        if pair.path.is_ident("min") {
+            min = Some(pair.value);
+        } else if pair.path.is_ident("max") {
+            max = Some(pair.value);
+        }
+    }
+
+    let min = min.ok_or_else(|| syn::Error::new_spanned(&list.path, "expected `min = ...`"))?;
+    let max = max.ok_or_else(|| syn::Error::new_spanned(&list.path, "expected `max = ...`"))?;
+    Ok((min, max))
+}
+
+fn lit_str_to_expr(expr: &Expr) -> syn::Result<Expr> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => s.parse(),
+            _ => Err(syn::Error::new_spanned(lit, "expected a string literal")),
+        },
+        other => Ok(other.clone()),
+    }
+}
+
+fn expr_to_type(expr: &Expr) -> syn::Result<Type> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => s.parse(),
+            _ => Err(syn::Error::new_spanned(lit, "expected a string literal naming a type")),
+        },
+        other => Err(syn::Error::new_spanned(other, "expected a string literal naming a type")),
+    }
+}
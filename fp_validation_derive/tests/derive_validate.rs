@@ -0,0 +1,146 @@
+use fp_validation::{NonEmptyVec, Validation};
+use fp_validation_derive::Validate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Email(String);
+
+impl Email {
+    fn validate(s: String) -> Validation<Self, String> {
+        if s.chars().filter(|c| *c == '@').count() == 1 {
+            Validation::Ok(Email(s))
+        } else {
+            Validation::Errs(s.into())
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FullName(String);
+
+impl FullName {
+    fn validate(s: String) -> Validation<Self, ()> {
+        if s.chars().all(|c| c.is_alphabetic() || c == ' ') {
+            Validation::Ok(FullName(s))
+        } else {
+            Validation::Errs(().into())
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PhoneNumber(String);
+
+impl PhoneNumber {
+    fn validate(s: String) -> Validation<Self, ()> {
+        let mut chars = s.chars();
+        if chars.next() == Some('+') && chars.all(|c| c.is_ascii_digit()) {
+            Validation::Ok(PhoneNumber(s))
+        } else {
+            Validation::Errs(().into())
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PersonRaw {
+    email: String,
+    name: String,
+    phone: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Validate)]
+#[validate(raw = "PersonRaw", error = "PersonValidationError")]
+struct Person {
+    #[validate(with = "Email::validate", map_errs = "|e| PersonValidationError::InvalidEmail(e.head)")]
+    email: Email,
+    #[validate(with = "FullName::validate", map_errs = "|_| PersonValidationError::InvalidFullName")]
+    name: FullName,
+    #[validate(with = "PhoneNumber::validate", map_errs = "|_| PersonValidationError::InvalidPhoneNumber")]
+    phone: PhoneNumber,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+enum PersonValidationError {
+    InvalidEmail(String),
+    InvalidFullName,
+    InvalidPhoneNumber,
+}
+
+#[test]
+fn validate_accepts_all_valid_fields() {
+    let raw = PersonRaw {
+        email: "valid.person@example.com".into(),
+        name: "Valid Person".into(),
+        phone: "+79991234567".into(),
+    };
+
+    let expected = Validation::Ok(Person {
+        email: Email("valid.person@example.com".into()),
+        name: FullName("Valid Person".into()),
+        phone: PhoneNumber("+79991234567".into()),
+    });
+
+    assert_eq!(expected, Person::validate(raw));
+}
+
+#[test]
+fn validate_accumulates_every_field_error_in_declaration_order() {
+    let raw = PersonRaw {
+        email: "✉".into(),
+        name: "😂".into(),
+        phone: "📞".into(),
+    };
+
+    let expected = Validation::Errs(NonEmptyVec::new(
+        PersonValidationError::InvalidEmail("✉".into()),
+        vec![PersonValidationError::InvalidFullName, PersonValidationError::InvalidPhoneNumber],
+    ));
+
+    assert_eq!(expected, Person::validate(raw));
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProfileRaw {
+    bio: String,
+    age: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Validate)]
+#[validate(raw = "ProfileRaw", error = "ProfileValidationError")]
+struct Profile {
+    #[validate(length(min = 1, max = 16), map_errs = "ProfileValidationError::InvalidBio")]
+    bio: String,
+    #[validate(range(min = 13, max = 120), map_errs = "|_| ProfileValidationError::InvalidAge")]
+    age: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+enum ProfileValidationError {
+    InvalidBio(NonEmptyVec<fp_validation::validators::ValidationError>),
+    InvalidAge,
+}
+
+#[test]
+fn validate_accepts_shorthand_fields_within_bounds() {
+    let raw = ProfileRaw { bio: "hello".into(), age: 30 };
+
+    let expected = Validation::Ok(Profile { bio: "hello".into(), age: 30 });
+
+    assert_eq!(expected, Profile::validate(raw));
+}
+
+#[test]
+fn validate_rejects_shorthand_fields_out_of_bounds() {
+    let raw = ProfileRaw { bio: "".into(), age: 9 };
+
+    let expected = Validation::Errs(NonEmptyVec::new(
+        ProfileValidationError::InvalidBio(
+            fp_validation::validators::ValidationError::TooShort { min: 1, actual: 0 }.into(),
+        ),
+        vec![ProfileValidationError::InvalidAge],
+    ));
+
+    assert_eq!(expected, Profile::validate(raw));
+}
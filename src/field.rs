@@ -0,0 +1,172 @@
+//! A builder for declaratively assembling field constraints, e.g.
+//! `Field::string().min_len(10).max_len(16).matches("+").validate(input)`,
+//! so a struct's `validate` doesn't need a bespoke sequence of
+//! [`crate::validators`] calls per field. Unlike calling the combinators
+//! directly, `Field` also understands `optional`/`default`: an absent
+//! optional field is `Ok(default)` rather than a `Missing` error.
+
+use regex::Regex;
+
+use crate::validation::Validation;
+use crate::validators::ValidationError;
+
+type Constraint<T> = Box<dyn Fn(&T) -> Validation<(), ValidationError>>;
+
+pub struct Field<T> {
+    constraints: Vec<Constraint<T>>,
+    optional: bool,
+    default: Option<T>,
+}
+
+impl Field<String> {
+    pub fn string() -> Self {
+        Field {
+            constraints: Vec::new(),
+            optional: false,
+            default: None,
+        }
+    }
+
+    pub fn min_len(mut self, min: usize) -> Self {
+        self.constraints.push(Box::new(move |s: &String| {
+            let actual = s.chars().count();
+            if actual < min {
+                Validation::Errs(ValidationError::TooShort { min, actual }.into())
+            } else {
+                Validation::Ok(())
+            }
+        }));
+        self
+    }
+
+    pub fn max_len(mut self, max: usize) -> Self {
+        self.constraints.push(Box::new(move |s: &String| {
+            let actual = s.chars().count();
+            if actual > max {
+                Validation::Errs(ValidationError::TooLong { max, actual }.into())
+            } else {
+                Validation::Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Adds a regex constraint, compiling `pattern` immediately so the
+    /// failure shows up when the `Field` is built rather than on first use.
+    /// `pattern` names the expected format at the call site (e.g. the
+    /// `^\+\d+$` a phone number field would use), so an invalid pattern
+    /// panics instead of quietly becoming a `PatternMismatch` on every input.
+    pub fn matches(mut self, pattern: &str) -> Self {
+        let pattern = Regex::new(pattern).expect("invalid regex pattern passed to Field::matches");
+        self.constraints.push(Box::new(move |s: &String| {
+            if pattern.is_match(s) {
+                Validation::Ok(())
+            } else {
+                Validation::Errs(ValidationError::PatternMismatch.into())
+            }
+        }));
+        self
+    }
+}
+
+impl<T> Field<T> {
+    /// Marks the field as allowed to be absent; an absent field without an
+    /// explicit [`Field::default`] falls back to `T::default()`.
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    pub fn default(mut self, value: T) -> Self {
+        self.default = Some(value);
+        self.optional = true;
+        self
+    }
+
+    pub fn validate(&self, input: Option<T>) -> Validation<T, ValidationError>
+    where
+        T: Clone + Default,
+    {
+        let value = match input {
+            Some(value) => value,
+            None if self.optional => return Validation::Ok(self.default.clone().unwrap_or_default()),
+            None => return Validation::Errs(ValidationError::Missing.into()),
+        };
+
+        let mut errors: Option<crate::non_empty_vec::NonEmptyVec<ValidationError>> = None;
+        for constraint in &self.constraints {
+            if let Validation::Errs(errs) = constraint(&value) {
+                match &mut errors {
+                    Some(accumulated) => accumulated.append(errs),
+                    None => errors = Some(errs),
+                }
+            }
+        }
+
+        match errors {
+            Some(errors) => Validation::Errs(errors),
+            None => Validation::Ok(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_field_runs_every_constraint_and_accumulates_failures() {
+        let field = Field::string().min_len(10).max_len(16).matches(r"^\+\d+$");
+
+        assert_eq!(Validation::Ok("+79991234567".to_string()), field.validate(Some("+79991234567".to_string())));
+    }
+
+    #[test]
+    fn required_field_accumulates_all_constraint_failures() {
+        let field = Field::string().min_len(10).max_len(16).matches(r"^\+\d+$");
+
+        assert_eq!(
+            Validation::Errs(
+                crate::non_empty_vec::NonEmptyVec::new(
+                    ValidationError::TooShort { min: 10, actual: 3 },
+                    vec![ValidationError::PatternMismatch],
+                )
+            ),
+            field.validate(Some("abc".to_string()))
+        );
+    }
+
+    #[test]
+    fn matches_rejects_substring_that_does_not_satisfy_the_pattern() {
+        let field = Field::string().matches(r"^\+\d+$");
+
+        assert_eq!(
+            Validation::Errs(ValidationError::PatternMismatch.into()),
+            field.validate(Some("+799-912-3456".to_string()))
+        );
+    }
+
+    #[test]
+    fn missing_required_field_is_an_error() {
+        let field = Field::string().min_len(10);
+
+        assert_eq!(
+            Validation::Errs(ValidationError::Missing.into()),
+            field.validate(None)
+        );
+    }
+
+    #[test]
+    fn missing_optional_field_falls_back_to_default() {
+        let field = Field::string().min_len(10).default("fallback".to_string());
+
+        assert_eq!(Validation::Ok("fallback".to_string()), field.validate(None));
+    }
+
+    #[test]
+    fn missing_optional_field_without_explicit_default_uses_type_default() {
+        let field = Field::string().optional();
+
+        assert_eq!(Validation::Ok(String::new()), field.validate(None));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod field;
+pub mod non_empty_vec;
+pub mod validation;
+pub mod validators;
+
+pub use field::Field;
+pub use fp_validation_derive::Validate;
+pub use non_empty_vec::NonEmptyVec;
+pub use validation::Validation;
@@ -60,6 +60,124 @@ impl<T, E> Validation<T, E> {
     {
         self.ap(other.map(|other| |self_| vec![self_, other].into_iter().collect()))
     }
+
+    /// Chains a dependent validation that only makes sense once `self`
+    /// succeeds, short-circuiting on the error case instead of
+    /// accumulating. This intentionally abandons applicative accumulation:
+    /// `f` cannot run without `self`'s value, so there is nothing to
+    /// accumulate its errors against.
+    pub fn and_then<U, F>(self, f: F) -> Validation<U, E>
+    where
+        F: FnOnce(T) -> Validation<U, E>,
+    {
+        match self {
+            Validation::Ok(value) => f(value),
+            Validation::Errs(errors) => Validation::Errs(errors),
+        }
+    }
+
+    pub fn into_result(self) -> Result<T, NonEmptyVec<E>> {
+        self.into()
+    }
+
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Validation::Ok(value) => Some(value),
+            Validation::Errs(_) => None,
+        }
+    }
+
+    pub fn errs(self) -> Option<NonEmptyVec<E>> {
+        match self {
+            Validation::Ok(_) => None,
+            Validation::Errs(errors) => Some(errors),
+        }
+    }
+}
+
+impl<T, E> From<Validation<T, E>> for Result<T, NonEmptyVec<E>> {
+    fn from(validation: Validation<T, E>) -> Self {
+        match validation {
+            Validation::Ok(value) => Ok(value),
+            Validation::Errs(errors) => Err(errors),
+        }
+    }
+}
+
+/// Combines two validations into a validation of the tuple of their values,
+/// accumulating errors from both sides in argument order. This is the
+/// fixed-arity counterpart to the curried `ap` chain: `zip2(a, b)` instead
+/// of `a.ap(b.map(|b| move |a| (a, b)))`.
+pub fn zip2<A, B, E>(a: Validation<A, E>, b: Validation<B, E>) -> Validation<(A, B), E> {
+    a.ap(b.map(|b| move |a| (a, b)))
+}
+
+pub fn zip3<A, B, C, E>(
+    a: Validation<A, E>,
+    b: Validation<B, E>,
+    c: Validation<C, E>,
+) -> Validation<(A, B, C), E> {
+    zip2(zip2(a, b), c).map(|((a, b), c)| (a, b, c))
+}
+
+pub fn zip4<A, B, C, D, E>(
+    a: Validation<A, E>,
+    b: Validation<B, E>,
+    c: Validation<C, E>,
+    d: Validation<D, E>,
+) -> Validation<(A, B, C, D), E> {
+    zip2(zip3(a, b, c), d).map(|((a, b, c), d)| (a, b, c, d))
+}
+
+pub fn zip5<A, B, C, D, F, E>(
+    a: Validation<A, E>,
+    b: Validation<B, E>,
+    c: Validation<C, E>,
+    d: Validation<D, E>,
+    f: Validation<F, E>,
+) -> Validation<(A, B, C, D, F), E> {
+    zip2(zip4(a, b, c, d), f).map(|((a, b, c, d), f)| (a, b, c, d, f))
+}
+
+pub fn zip6<A, B, C, D, F, G, E>(
+    a: Validation<A, E>,
+    b: Validation<B, E>,
+    c: Validation<C, E>,
+    d: Validation<D, E>,
+    f: Validation<F, E>,
+    g: Validation<G, E>,
+) -> Validation<(A, B, C, D, F, G), E> {
+    zip2(zip5(a, b, c, d, f), g).map(|((a, b, c, d, f), g)| (a, b, c, d, f, g))
+}
+
+impl<T, U, E> std::ops::Add<Validation<U, E>> for Validation<T, E> {
+    type Output = Validation<(T, U), E>;
+
+    fn add(self, other: Validation<U, E>) -> Self::Output {
+        zip2(self, other)
+    }
+}
+
+/// Combines 2 to 6 validations by dispatching to the matching `zipN`,
+/// so callers can write `validate!(a, b, c).map(|(a, b, c)| ...)` instead
+/// of a nested curried `ap` chain.
+#[macro_export]
+macro_rules! validate {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::validation::zip2($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)?) => {
+        $crate::validation::zip3($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {
+        $crate::validation::zip4($a, $b, $c, $d)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr $(,)?) => {
+        $crate::validation::zip5($a, $b, $c, $d, $e)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr $(,)?) => {
+        $crate::validation::zip6($a, $b, $c, $d, $e, $f)
+    };
 }
 
 impl<T, E> Default for Validation<T, E>
@@ -173,7 +291,7 @@ mod tests {
     impl PhoneNumber {
         pub fn validate(s: String) -> Validation<Self, PhoneNumberValidationError> {
             let len = s.len();
-            let length_validation = if len > 16 || len < 10 {
+            let length_validation = if !(10..=16).contains(&len) {
                 Validation::Errs(PhoneNumberValidationError::LengthOutOfRange.into())
             } else {
                 Validation::Ok(())
@@ -208,6 +326,9 @@ mod tests {
     }
 
     #[derive(Debug, Clone, PartialEq, Eq)]
+    // The shared `Invalid` prefix names what went wrong with each field, not
+    // an accidental repetition of the enum's own name.
+    #[allow(clippy::enum_variant_names)]
     pub enum PersonValidationError {
         InvalidEmail(String),
         InvalidFullName,
@@ -253,10 +374,7 @@ mod tests {
             Email::validate("bob@example.com".into()),
         ];
 
-        let expected = Validation::Errs(NonEmptyVec {
-            head: "✉".into(),
-            tail: vec![],
-        });
+        let expected = Validation::Errs(NonEmptyVec::new("✉".into(), vec![]));
         let validation = email_validations
             .into_iter()
             .collect::<Validation<Vec<_>, String>>();
@@ -268,10 +386,7 @@ mod tests {
     pub fn validation_from_iterator_invalid_all() {
         let email_validations = vec![Email::validate("✉".into()), Email::validate(":3".into())];
 
-        let expected = Validation::Errs(NonEmptyVec {
-            head: "✉".into(),
-            tail: vec![":3".into()],
-        });
+        let expected = Validation::Errs(NonEmptyVec::new("✉".into(), vec![":3".into()]));
         let validation = email_validations
             .into_iter()
             .collect::<Validation<Vec<_>, String>>();
@@ -305,10 +420,7 @@ mod tests {
             phone: "+79991234567".into(),
         };
 
-        let expected = Validation::Errs(NonEmptyVec {
-            head: PersonValidationError::InvalidEmail("✉".into()),
-            tail: vec![],
-        });
+        let expected = Validation::Errs(NonEmptyVec::new(PersonValidationError::InvalidEmail("✉".into()), vec![]));
         let validation = Person::validate(valid_person_raw);
 
         assert_eq!(expected, validation);
@@ -322,18 +434,124 @@ mod tests {
             phone: "📞".into(),
         };
 
-        let expected = Validation::Errs(NonEmptyVec {
-            head: PersonValidationError::InvalidEmail("✉".into()),
-            tail: vec![
+        let expected = Validation::Errs(NonEmptyVec::new(
+            PersonValidationError::InvalidEmail("✉".into()),
+            vec![
                 PersonValidationError::InvalidFullName,
-                PersonValidationError::InvalidPhoneNumber(NonEmptyVec {
-                    head: PhoneNumberValidationError::LengthOutOfRange,
-                    tail: vec![PhoneNumberValidationError::InvalidFormat],
-                }),
+                PersonValidationError::InvalidPhoneNumber(NonEmptyVec::new(
+                    PhoneNumberValidationError::LengthOutOfRange,
+                    vec![PhoneNumberValidationError::InvalidFormat],
+                )),
             ],
-        });
+        ));
         let validation = Person::validate(valid_person_raw);
 
         assert_eq!(expected, validation);
     }
+
+    #[test]
+    pub fn validation_zip2_combines_ok_values_into_tuple() {
+        let validation = super::zip2(
+            Email::validate("alice@example.com".into()),
+            Email::validate("bob@example.com".into()),
+        );
+
+        assert_eq!(
+            Validation::Ok((
+                Email("alice@example.com".into()),
+                Email("bob@example.com".into())
+            )),
+            validation
+        );
+    }
+
+    #[test]
+    pub fn validation_zip2_accumulates_both_errors() {
+        let validation = super::zip2(Email::validate("✉".into()), Email::validate(":3".into()));
+
+        assert_eq!(
+            Validation::Errs(NonEmptyVec::new("✉".to_string(), vec![":3".to_string()])),
+            validation
+        );
+    }
+
+    #[test]
+    pub fn validation_validate_macro_dispatches_to_matching_zip_arity() {
+        let validation = crate::validate!(
+            Email::validate("✉".into()),
+            Email::validate("alice@example.com".into()),
+            Email::validate(":3".into())
+        );
+
+        assert_eq!(
+            Validation::Errs(NonEmptyVec::new("✉".to_string(), vec![":3".to_string()])),
+            validation
+        );
+    }
+
+    #[test]
+    pub fn validation_add_operator_combines_values() {
+        let validation =
+            Email::validate("alice@example.com".into()) + Email::validate("bob@example.com".into());
+
+        assert_eq!(
+            Validation::Ok((
+                Email("alice@example.com".into()),
+                Email("bob@example.com".into())
+            )),
+            validation
+        );
+    }
+
+    #[test]
+    pub fn validation_and_then_runs_dependent_validation_on_ok() {
+        let validation = Email::validate("alice@example.com".into())
+            .and_then(|email| Email::validate("bob@example.com".into()).map(|other| (email, other)));
+
+        assert_eq!(
+            Validation::Ok((
+                Email("alice@example.com".into()),
+                Email("bob@example.com".into())
+            )),
+            validation
+        );
+    }
+
+    #[test]
+    pub fn validation_and_then_short_circuits_on_errs_without_running_f() {
+        let validation = Email::validate("✉".into())
+            .and_then(|_| -> Validation<Email, String> { panic!("f must not run") });
+
+        assert_eq!(
+            Validation::Errs(NonEmptyVec::new("✉".to_string(), vec![])),
+            validation
+        );
+    }
+
+    #[test]
+    pub fn validation_into_result_converts_ok_and_errs() {
+        assert_eq!(
+            Ok(Email("alice@example.com".into())),
+            Email::validate("alice@example.com".into()).into_result()
+        );
+        assert_eq!(
+            Err(NonEmptyVec::new("✉".to_string(), vec![])),
+            Email::validate("✉".into()).into_result()
+        );
+    }
+
+    #[test]
+    pub fn validation_ok_and_errs_project_to_option() {
+        assert_eq!(
+            Some(Email("alice@example.com".into())),
+            Email::validate("alice@example.com".into()).ok()
+        );
+        assert_eq!(None, Email::validate("✉".into()).ok());
+
+        assert_eq!(None, Email::validate("alice@example.com".into()).errs());
+        assert_eq!(
+            Some(NonEmptyVec::new("✉".to_string(), vec![])),
+            Email::validate("✉".into()).errs()
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,263 @@
+//! Reusable validation combinators, so that common field checks (length,
+//! range, email, url, ...) don't need to be hand rolled by every caller the
+//! way [`crate::validation`]'s doctest `Email`/`FullName`/`PhoneNumber`
+//! types do. Each combinator returns a [`Validation`] so it composes with
+//! [`Validation::merge`] and [`Validation::ap`].
+
+use std::net::IpAddr;
+
+use regex::Regex;
+
+use crate::validation::Validation;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    TooShort { min: usize, actual: usize },
+    TooLong { max: usize, actual: usize },
+    OutOfRange,
+    InvalidEmail,
+    InvalidUrl,
+    InvalidIp,
+    PatternMismatch,
+    MissingSubstring,
+    UnexpectedSubstring,
+    ControlCharacter,
+    Mismatch,
+    Missing,
+}
+
+pub fn length(min: usize, max: usize) -> impl Fn(&str) -> Validation<(), ValidationError> {
+    move |s: &str| {
+        let actual = s.chars().count();
+        if actual < min {
+            Validation::Errs(ValidationError::TooShort { min, actual }.into())
+        } else if actual > max {
+            Validation::Errs(ValidationError::TooLong { max, actual }.into())
+        } else {
+            Validation::Ok(())
+        }
+    }
+}
+
+pub fn range<T>(min: T, max: T) -> impl Fn(T) -> Validation<T, ValidationError>
+where
+    T: PartialOrd + Copy,
+{
+    move |value: T| {
+        if value < min || value > max {
+            Validation::Errs(ValidationError::OutOfRange.into())
+        } else {
+            Validation::Ok(value)
+        }
+    }
+}
+
+pub fn email(s: &str) -> Validation<(), ValidationError> {
+    let at_count = s.chars().filter(|c| *c == '@').count();
+    let valid = at_count == 1
+        && s.split('@').next().is_some_and(|local| !local.is_empty())
+        && s.split('@').nth(1).is_some_and(|domain| {
+            domain.contains('.') && !domain.starts_with('.') && !domain.ends_with('.')
+        });
+
+    if valid {
+        Validation::Ok(())
+    } else {
+        Validation::Errs(ValidationError::InvalidEmail.into())
+    }
+}
+
+pub fn url(s: &str) -> Validation<(), ValidationError> {
+    let valid = ["http://", "https://"]
+        .iter()
+        .any(|scheme| s.starts_with(scheme))
+        && s.len() > "https://".len();
+
+    if valid {
+        Validation::Ok(())
+    } else {
+        Validation::Errs(ValidationError::InvalidUrl.into())
+    }
+}
+
+pub fn ip(s: &str) -> Validation<IpAddr, ValidationError> {
+    match s.parse::<IpAddr>() {
+        Ok(addr) => Validation::Ok(addr),
+        Err(_) => Validation::Errs(ValidationError::InvalidIp.into()),
+    }
+}
+
+/// Builds a regex-backed combinator. `pattern` is compiled once here, so the
+/// returned closure only has to run the match on each call. `pattern` is a
+/// programmer-supplied constant describing the expected format, not
+/// end-user input, so an invalid pattern panics immediately rather than
+/// surfacing as a per-call `Validation::Errs`.
+pub fn regex(pattern: &str) -> impl Fn(&str) -> Validation<(), ValidationError> {
+    let pattern = Regex::new(pattern).expect("invalid regex pattern passed to validators::regex");
+    move |s: &str| {
+        if pattern.is_match(s) {
+            Validation::Ok(())
+        } else {
+            Validation::Errs(ValidationError::PatternMismatch.into())
+        }
+    }
+}
+
+pub fn contains(substr: &str) -> impl Fn(&str) -> Validation<(), ValidationError> + '_ {
+    move |s: &str| {
+        if s.contains(substr) {
+            Validation::Ok(())
+        } else {
+            Validation::Errs(ValidationError::MissingSubstring.into())
+        }
+    }
+}
+
+pub fn does_not_contain(substr: &str) -> impl Fn(&str) -> Validation<(), ValidationError> + '_ {
+    move |s: &str| {
+        if s.contains(substr) {
+            Validation::Errs(ValidationError::UnexpectedSubstring.into())
+        } else {
+            Validation::Ok(())
+        }
+    }
+}
+
+pub fn non_control_character(s: &str) -> Validation<(), ValidationError> {
+    if s.chars().any(|c| c.is_control()) {
+        Validation::Errs(ValidationError::ControlCharacter.into())
+    } else {
+        Validation::Ok(())
+    }
+}
+
+pub fn must_match<'a>(other: &'a str) -> impl Fn(&str) -> Validation<(), ValidationError> + 'a {
+    move |s: &str| {
+        if s == other {
+            Validation::Ok(())
+        } else {
+            Validation::Errs(ValidationError::Mismatch.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_accepts_within_bounds() {
+        assert_eq!(Validation::Ok(()), length(2, 4)("abc"));
+    }
+
+    #[test]
+    fn length_rejects_too_short() {
+        assert_eq!(
+            Validation::Errs(ValidationError::TooShort { min: 2, actual: 1 }.into()),
+            length(2, 4)("a")
+        );
+    }
+
+    #[test]
+    fn length_rejects_too_long() {
+        assert_eq!(
+            Validation::Errs(ValidationError::TooLong { max: 4, actual: 5 }.into()),
+            length(2, 4)("abcde")
+        );
+    }
+
+    #[test]
+    fn range_accepts_within_bounds() {
+        assert_eq!(Validation::Ok(5), range(0, 10)(5));
+    }
+
+    #[test]
+    fn range_rejects_out_of_bounds() {
+        assert_eq!(
+            Validation::Errs(ValidationError::OutOfRange.into()),
+            range(0, 10)(11)
+        );
+    }
+
+    #[test]
+    fn email_accepts_well_formed_address() {
+        assert_eq!(Validation::Ok(()), email("alice@example.com"));
+    }
+
+    #[test]
+    fn email_rejects_missing_at() {
+        assert_eq!(
+            Validation::Errs(ValidationError::InvalidEmail.into()),
+            email("alice.example.com")
+        );
+    }
+
+    #[test]
+    fn url_accepts_https() {
+        assert_eq!(Validation::Ok(()), url("https://example.com"));
+    }
+
+    #[test]
+    fn url_rejects_missing_scheme() {
+        assert_eq!(
+            Validation::Errs(ValidationError::InvalidUrl.into()),
+            url("example.com")
+        );
+    }
+
+    #[test]
+    fn ip_accepts_v4_and_v6() {
+        assert!(matches!(ip("127.0.0.1"), Validation::Ok(_)));
+        assert!(matches!(ip("::1"), Validation::Ok(_)));
+    }
+
+    #[test]
+    fn ip_rejects_garbage() {
+        assert_eq!(Validation::Errs(ValidationError::InvalidIp.into()), ip("not-an-ip"));
+    }
+
+    #[test]
+    fn regex_accepts_matching_pattern() {
+        assert_eq!(Validation::Ok(()), regex(r"^\+\d+$")("+79991234567"));
+    }
+
+    #[test]
+    fn regex_rejects_non_matching_pattern() {
+        assert_eq!(
+            Validation::Errs(ValidationError::PatternMismatch.into()),
+            regex(r"^\+\d+$")("+7999abc4567")
+        );
+    }
+
+    #[test]
+    fn contains_and_does_not_contain() {
+        assert_eq!(Validation::Ok(()), contains("@")("a@b"));
+        assert_eq!(
+            Validation::Errs(ValidationError::MissingSubstring.into()),
+            contains("@")("ab")
+        );
+        assert_eq!(Validation::Ok(()), does_not_contain("@")("ab"));
+        assert_eq!(
+            Validation::Errs(ValidationError::UnexpectedSubstring.into()),
+            does_not_contain("@")("a@b")
+        );
+    }
+
+    #[test]
+    fn non_control_character_rejects_control_chars() {
+        assert_eq!(Validation::Ok(()), non_control_character("abc"));
+        assert_eq!(
+            Validation::Errs(ValidationError::ControlCharacter.into()),
+            non_control_character("a\u{0}b")
+        );
+    }
+
+    #[test]
+    fn must_match_compares_against_other_field() {
+        assert_eq!(Validation::Ok(()), must_match("secret")("secret"));
+        assert_eq!(
+            Validation::Errs(ValidationError::Mismatch.into()),
+            must_match("secret")("nope")
+        );
+    }
+}